@@ -2,7 +2,22 @@
 
 #![allow(clippy::needless_range_loop, clippy::many_single_char_names)]
 
+mod bits;
+mod cmp;
 mod decoder;
+mod div;
+#[cfg(feature = "alloc")]
+mod encoding;
+mod inv_mod;
+mod modular;
+mod mul;
+mod non_zero;
+#[cfg(feature = "rand_core")]
+mod rand;
+mod sqrt;
+
+pub use self::modular::{MontgomeryParams, Residue};
+pub use self::non_zero::NonZero;
 
 use self::decoder::Decoder;
 use crate::{limb, Concat, Limb, NumBits, NumBytes, Split, LIMB_BYTES};
@@ -255,6 +270,11 @@ impl<const LIMBS: usize> UInt<LIMBS> {
     }
 
     /// Compute "wide" multiplication, with a product twice the size of the input.
+    ///
+    /// Always uses schoolbook multiplication, so it works in `const`
+    /// contexts. For large `LIMBS` counts where the input isn't known at
+    /// compile time, prefer [`Self::mul_wide_fast`], which dispatches to a
+    /// Karatsuba implementation above a tuning threshold.
     // TODO(tarcieri): use `concat` (or replacement) when traits are const-friendly
     pub const fn mul_wide(&self, rhs: &Self) -> (Self, Self) {
         let mut i = 0;
@@ -262,7 +282,6 @@ impl<const LIMBS: usize> UInt<LIMBS> {
         let mut hi = Self::ZERO;
 
         // Schoolbook multiplication.
-        // TODO(tarcieri): use Karatsuba for better performance?
         while i < LIMBS {
             let mut j = 0;
             let mut carry = 0;
@@ -357,7 +376,7 @@ impl<const LIMBS: usize> ConditionallySelectable for UInt<LIMBS> {
         let mut limbs = [0; LIMBS];
 
         for i in 0..LIMBS {
-            limbs[i] = Limb::conditional_select(&a.limbs[0], &b.limbs[0], choice);
+            limbs[i] = Limb::conditional_select(&a.limbs[i], &b.limbs[i], choice);
         }
 
         Self { limbs }
@@ -0,0 +1,325 @@
+//! Bitwise logic, shifts, and bit-introspection for [`UInt`].
+
+use super::UInt;
+use crate::{Limb, LIMB_BYTES};
+use core::ops;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+/// Number of bits in a single [`Limb`].
+const LIMB_BITS: usize = LIMB_BYTES * 8;
+
+impl<const LIMBS: usize> UInt<LIMBS> {
+    /// Compute the bitwise AND of `self` and `rhs`.
+    pub fn bitand(&self, rhs: &Self) -> Self {
+        let mut limbs = [0; LIMBS];
+
+        for i in 0..LIMBS {
+            limbs[i] = self.limbs[i] & rhs.limbs[i];
+        }
+
+        Self { limbs }
+    }
+
+    /// Compute the bitwise OR of `self` and `rhs`.
+    pub fn bitor(&self, rhs: &Self) -> Self {
+        let mut limbs = [0; LIMBS];
+
+        for i in 0..LIMBS {
+            limbs[i] = self.limbs[i] | rhs.limbs[i];
+        }
+
+        Self { limbs }
+    }
+
+    /// Compute the bitwise XOR of `self` and `rhs`.
+    pub fn bitxor(&self, rhs: &Self) -> Self {
+        let mut limbs = [0; LIMBS];
+
+        for i in 0..LIMBS {
+            limbs[i] = self.limbs[i] ^ rhs.limbs[i];
+        }
+
+        Self { limbs }
+    }
+
+    /// Compute the bitwise NOT of `self`.
+    pub fn not(&self) -> Self {
+        let mut limbs = [0; LIMBS];
+
+        for i in 0..LIMBS {
+            limbs[i] = !self.limbs[i];
+        }
+
+        Self { limbs }
+    }
+
+    /// Get the value of the bit at `index`, as a [`Choice`].
+    pub fn bit(&self, index: usize) -> Choice {
+        let limb = self.limbs[index / LIMB_BITS];
+        Choice::from(((limb >> (index % LIMB_BITS)) & 1) as u8)
+    }
+
+    /// Number of bits needed to represent this value, i.e. the index of the
+    /// most significant set bit, plus one. Returns `0` for a value of zero.
+    pub fn bits(&self) -> usize {
+        LIMBS * LIMB_BITS - self.leading_zeros()
+    }
+
+    /// Number of leading zero bits, counting from the most significant limb
+    /// down. Every limb is inspected regardless of where the leading zeros
+    /// end, so the running time does not depend on the value.
+    pub fn leading_zeros(&self) -> usize {
+        let mut total: usize = 0;
+        let mut done = Choice::from(0);
+
+        let mut i = LIMBS;
+        while i > 0 {
+            i -= 1;
+
+            let limb = self.limbs[i];
+            let limb_is_zero = limb.ct_eq(&0);
+            let add_full = !done & limb_is_zero;
+            let add_partial = !done & !limb_is_zero;
+
+            let contribution = u32::conditional_select(
+                &u32::conditional_select(&0, &limb.leading_zeros(), add_partial),
+                &(LIMB_BITS as u32),
+                add_full,
+            );
+
+            total += contribution as usize;
+            done |= !limb_is_zero;
+        }
+
+        total
+    }
+
+    /// Number of trailing zero bits, counting from the least significant
+    /// limb up. Every limb is inspected regardless of where the trailing
+    /// zeros end, so the running time does not depend on the value.
+    pub fn trailing_zeros(&self) -> usize {
+        let mut total: usize = 0;
+        let mut done = Choice::from(0);
+
+        for i in 0..LIMBS {
+            let limb = self.limbs[i];
+            let limb_is_zero = limb.ct_eq(&0);
+            let add_full = !done & limb_is_zero;
+            let add_partial = !done & !limb_is_zero;
+
+            let contribution = u32::conditional_select(
+                &u32::conditional_select(&0, &limb.trailing_zeros(), add_partial),
+                &(LIMB_BITS as u32),
+                add_full,
+            );
+
+            total += contribution as usize;
+            done |= !limb_is_zero;
+        }
+
+        total
+    }
+
+    /// Computes `self << shift` in constant time with respect to `shift`.
+    ///
+    /// Tries every possible whole-limb shift amount in `0..=LIMBS` and
+    /// selects the matching one, then shifts the remaining sub-limb amount
+    /// using `limb << sh | prev_limb >> (LIMB_BITS - sh)`, so the number of
+    /// limbs touched never depends on the value of `shift`.
+    pub fn shl(&self, shift: usize) -> Self {
+        let shift_limbs = shift / LIMB_BITS;
+        let shift_bits = (shift % LIMB_BITS) as u32;
+
+        let mut result = Self::ZERO;
+        let mut k = 0;
+        while k <= LIMBS {
+            let candidate = self.shl_limbs(k);
+            let is_match = Choice::from((k == shift_limbs) as u8);
+            result = Self::conditional_select(&result, &candidate, is_match);
+            k += 1;
+        }
+
+        result.shl_bits(shift_bits)
+    }
+
+    /// Computes `self >> shift` in constant time with respect to `shift`.
+    pub fn shr(&self, shift: usize) -> Self {
+        let shift_limbs = shift / LIMB_BITS;
+        let shift_bits = (shift % LIMB_BITS) as u32;
+
+        let mut result = Self::ZERO;
+        let mut k = 0;
+        while k <= LIMBS {
+            let candidate = self.shr_limbs(k);
+            let is_match = Choice::from((k == shift_limbs) as u8);
+            result = Self::conditional_select(&result, &candidate, is_match);
+            k += 1;
+        }
+
+        result.shr_bits(shift_bits)
+    }
+
+    /// Shift left by exactly `k` whole limbs.
+    fn shl_limbs(&self, k: usize) -> Self {
+        let mut limbs = [0; LIMBS];
+
+        for i in 0..LIMBS {
+            if i >= k {
+                limbs[i] = self.limbs[i - k];
+            }
+        }
+
+        Self { limbs }
+    }
+
+    /// Shift right by exactly `k` whole limbs.
+    fn shr_limbs(&self, k: usize) -> Self {
+        let mut limbs = [0; LIMBS];
+
+        for i in 0..LIMBS {
+            if i + k < LIMBS {
+                limbs[i] = self.limbs[i + k];
+            }
+        }
+
+        Self { limbs }
+    }
+
+    /// Shift left by `sh` bits, where `0 <= sh < LIMB_BITS`.
+    fn shl_bits(&self, sh: u32) -> Self {
+        let is_zero = Choice::from((sh == 0) as u8);
+        let hi_shift = (LIMB_BITS as u32).wrapping_sub(sh) % LIMB_BITS as u32;
+
+        let mut limbs = [0; LIMBS];
+        let mut i = LIMBS;
+        while i > 0 {
+            i -= 1;
+
+            let prev = if i == 0 { 0 } else { self.limbs[i - 1] };
+            let carried_in = Limb::conditional_select(&(prev >> hi_shift), &0, is_zero);
+            limbs[i] = (self.limbs[i] << sh) | carried_in;
+        }
+
+        Self { limbs }
+    }
+
+    /// Shift right by `sh` bits, where `0 <= sh < LIMB_BITS`.
+    fn shr_bits(&self, sh: u32) -> Self {
+        let is_zero = Choice::from((sh == 0) as u8);
+        let hi_shift = (LIMB_BITS as u32).wrapping_sub(sh) % LIMB_BITS as u32;
+
+        let mut limbs = [0; LIMBS];
+        for i in 0..LIMBS {
+            let next = if i + 1 == LIMBS { 0 } else { self.limbs[i + 1] };
+            let carried_in = Limb::conditional_select(&(next << hi_shift), &0, is_zero);
+            limbs[i] = (self.limbs[i] >> sh) | carried_in;
+        }
+
+        Self { limbs }
+    }
+}
+
+impl<const LIMBS: usize> ops::BitAnd for UInt<LIMBS> {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        UInt::bitand(&self, &rhs)
+    }
+}
+
+impl<const LIMBS: usize> ops::BitOr for UInt<LIMBS> {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        UInt::bitor(&self, &rhs)
+    }
+}
+
+impl<const LIMBS: usize> ops::BitXor for UInt<LIMBS> {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self {
+        UInt::bitxor(&self, &rhs)
+    }
+}
+
+impl<const LIMBS: usize> ops::Not for UInt<LIMBS> {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        UInt::not(&self)
+    }
+}
+
+impl<const LIMBS: usize> ops::Shl<usize> for UInt<LIMBS> {
+    type Output = Self;
+
+    fn shl(self, shift: usize) -> Self {
+        UInt::shl(&self, shift)
+    }
+}
+
+impl<const LIMBS: usize> ops::Shr<usize> for UInt<LIMBS> {
+    type Output = Self;
+
+    fn shr(self, shift: usize) -> Self {
+        UInt::shr(&self, shift)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::U256;
+
+    #[test]
+    fn bitwise_ops_match_native() {
+        let a = U256::from_u64(0b1100);
+        let b = U256::from_u64(0b1010);
+
+        assert_eq!(a.bitand(&b), U256::from_u64(0b1000));
+        assert_eq!(a.bitor(&b), U256::from_u64(0b1110));
+        assert_eq!(a.bitxor(&b), U256::from_u64(0b0110));
+        assert_eq!(a.not().bitand(&U256::from_u64(0xff)), U256::from_u64(0xf3));
+    }
+
+    #[test]
+    fn bit_reads_the_expected_position() {
+        let a = U256::from_u64(0b1010);
+        assert!(!bool::from(a.bit(0)));
+        assert!(bool::from(a.bit(1)));
+        assert!(!bool::from(a.bit(2)));
+        assert!(bool::from(a.bit(3)));
+    }
+
+    #[test]
+    fn bits_and_leading_trailing_zeros_match_native() {
+        assert_eq!(U256::ZERO.bits(), 0);
+        assert_eq!(U256::from_u64(1).bits(), 1);
+        assert_eq!(U256::from_u64(0xff).bits(), 8);
+        assert_eq!(U256::from_u64(0x100).bits(), 9);
+
+        assert_eq!(U256::ZERO.leading_zeros(), 256);
+        assert_eq!(U256::from_u64(1).leading_zeros(), 255);
+        assert_eq!(U256::from_u64(1).trailing_zeros(), 0);
+        assert_eq!(U256::from_u64(0x100).trailing_zeros(), 8);
+    }
+
+    #[test]
+    fn shl_and_shr_match_native_for_small_shifts() {
+        for shift in 0usize..40 {
+            let a = U256::from_u64(0x1234_5678);
+            let shl = a.shl(shift);
+            let shr = shl.shr(shift);
+            assert_eq!(shr, a, "shl/shr round-trip failed for shift {shift}");
+        }
+
+        assert_eq!(U256::from_u64(1).shl(8), U256::from_u64(0x100));
+        assert_eq!(U256::from_u64(0x100).shr(8), U256::from_u64(1));
+    }
+
+    #[test]
+    fn shl_past_the_top_limb_is_zero() {
+        assert_eq!(U256::from_u64(1).shl(256), U256::ZERO);
+        assert_eq!(U256::MAX.shr(256), U256::ZERO);
+    }
+}
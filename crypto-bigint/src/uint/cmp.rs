@@ -0,0 +1,132 @@
+//! Constant-time comparisons of [`UInt`].
+
+use super::UInt;
+use crate::limb;
+use core::cmp::Ordering;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+impl<const LIMBS: usize> UInt<LIMBS> {
+    /// Returns the truthy value if `self < rhs` and the falsy value otherwise.
+    ///
+    /// Every limb is inspected regardless of where the two values first
+    /// differ, so the running time does not depend on the position of the
+    /// differing limb.
+    pub fn ct_lt(&self, rhs: &Self) -> Choice {
+        self.ct_cmp_choices(rhs).0
+    }
+
+    /// Returns the truthy value if `self > rhs` and the falsy value otherwise.
+    pub fn ct_gt(&self, rhs: &Self) -> Choice {
+        self.ct_cmp_choices(rhs).1
+    }
+
+    /// Compare `self` to `rhs`, returning an [`Ordering`].
+    pub fn ct_cmp(&self, rhs: &Self) -> Ordering {
+        let (lt, gt) = self.ct_cmp_choices(rhs);
+
+        if bool::from(lt) {
+            Ordering::Less
+        } else if bool::from(gt) {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+
+    /// Return the larger of `self` and `rhs`.
+    ///
+    /// Takes both operands by value (matching [`Ord::max`]'s signature, not
+    /// `ct_lt`/`ct_gt`'s) so this inherent method isn't shadowed by the
+    /// identically-named trait method once [`Ord`] is in scope: method
+    /// resolution tries the by-value receiver first, and an inherent/trait
+    /// method pair only coexists cleanly when their signatures match.
+    pub fn max(self, rhs: Self) -> Self {
+        Self::conditional_select(&self, &rhs, self.ct_lt(&rhs))
+    }
+
+    /// Return the smaller of `self` and `rhs`. See [`Self::max`] for why this
+    /// takes its operands by value.
+    pub fn min(self, rhs: Self) -> Self {
+        Self::conditional_select(&self, &rhs, self.ct_gt(&rhs))
+    }
+
+    /// Compute `(lt, gt)` choices, walking limbs from most- to
+    /// least-significant so that a difference in a more significant limb
+    /// always dominates the result regardless of what follows in the less
+    /// significant limbs.
+    fn ct_cmp_choices(&self, rhs: &Self) -> (Choice, Choice) {
+        let mut lt = Choice::from(0);
+        let mut gt = Choice::from(0);
+        let mut decided = Choice::from(0);
+
+        let mut i = LIMBS;
+        while i > 0 {
+            i -= 1;
+
+            let (_, borrow) = limb::sbb(self.limbs[i], rhs.limbs[i], 0);
+            let limb_lt = Choice::from((borrow as u8) & 1);
+            let limb_eq = self.limbs[i].ct_eq(&rhs.limbs[i]);
+            let limb_gt = !limb_lt & !limb_eq;
+
+            let undecided = !decided;
+            lt |= undecided & limb_lt;
+            gt |= undecided & limb_gt;
+            decided |= !limb_eq;
+        }
+
+        (lt, gt)
+    }
+}
+
+impl<const LIMBS: usize> PartialOrd for UInt<LIMBS> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const LIMBS: usize> Ord for UInt<LIMBS> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.ct_cmp(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::U256;
+    use core::cmp::Ordering;
+
+    #[test]
+    fn ct_lt_gt_cmp_match_native() {
+        for a in 0u64..20 {
+            for b in 0u64..20 {
+                let ua = U256::from_u64(a);
+                let ub = U256::from_u64(b);
+
+                assert_eq!(bool::from(ua.ct_lt(&ub)), a < b);
+                assert_eq!(bool::from(ua.ct_gt(&ub)), a > b);
+                assert_eq!(ua.ct_cmp(&ub), a.cmp(&b));
+            }
+        }
+    }
+
+    #[test]
+    fn ordering_matches_native() {
+        let a = U256::from_u64(41);
+        let b = U256::from_u64(42);
+        assert_eq!(a.cmp(&b), Ordering::Less);
+        assert_eq!(b.cmp(&a), Ordering::Greater);
+        assert_eq!(a.cmp(&a), Ordering::Equal);
+    }
+
+    #[test]
+    fn max_and_min_match_native() {
+        for a in 0u64..20 {
+            for b in 0u64..20 {
+                let ua = U256::from_u64(a);
+                let ub = U256::from_u64(b);
+                assert_eq!(ua.max(ub), U256::from_u64(a.max(b)));
+                assert_eq!(ua.min(ub), U256::from_u64(a.min(b)));
+            }
+        }
+    }
+}
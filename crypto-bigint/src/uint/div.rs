@@ -0,0 +1,117 @@
+//! Constant-time division for [`UInt`].
+
+use super::non_zero::NonZero;
+use super::UInt;
+use crate::{Limb, LIMB_BYTES};
+use subtle::{Choice, ConditionallySelectable, CtOption};
+
+/// Number of bits in a single [`Limb`](crate::Limb).
+const LIMB_BITS: usize = LIMB_BYTES * 8;
+
+impl<const LIMBS: usize> UInt<LIMBS> {
+    /// Compute `self / rhs` and `self % rhs`, returning the quotient and
+    /// remainder.
+    ///
+    /// Implements constant-time binary long division: the dividend is
+    /// consumed one bit at a time from the most significant bit down, so
+    /// the number of loop iterations -- and therefore the running time --
+    /// never depends on the operands' values.
+    pub fn div_rem(&self, rhs: &NonZero<Self>) -> (Self, Self) {
+        let rhs = rhs.get();
+        let mut quotient = Self::ZERO;
+        let mut remainder = Self::ZERO;
+
+        let mut i = LIMBS * LIMB_BITS;
+        while i > 0 {
+            i -= 1;
+
+            let (mut shifted, carry) = remainder.shl1();
+            let dividend_bit = (self.limbs[i / LIMB_BITS] >> (i % LIMB_BITS)) & 1;
+            shifted.limbs[0] |= dividend_bit;
+
+            let (diff, borrow) = shifted.sbb(&rhs, 0);
+            let borrowed = Choice::from((borrow as u8) & 1);
+
+            // A bit carried out of the top of `shifted` means the true,
+            // unbounded value is at least `2^(LIMBS * LIMB_BITS)`, which
+            // always exceeds `rhs`; the subtraction is then guaranteed to
+            // apply, regardless of what the (necessarily truncated) borrow
+            // flag above says.
+            let subtract = carry | !borrowed;
+
+            remainder = Self::conditional_select(&shifted, &diff, subtract);
+            quotient.limbs[i / LIMB_BITS] |= (subtract.unwrap_u8() as Limb) << (i % LIMB_BITS);
+        }
+
+        (quotient, remainder)
+    }
+
+    /// Compute `self % rhs`.
+    pub fn rem(&self, rhs: &NonZero<Self>) -> Self {
+        self.div_rem(rhs).1
+    }
+
+    /// Compute `self / rhs`.
+    pub fn wrapping_div(&self, rhs: &NonZero<Self>) -> Self {
+        self.div_rem(rhs).0
+    }
+
+    /// Perform checked division, returning [`CtOption`] only if `rhs` is
+    /// nonzero.
+    pub fn checked_div(&self, rhs: &Self) -> CtOption<Self> {
+        NonZero::new(*rhs).map(|rhs| self.wrapping_div(&rhs))
+    }
+
+    /// Perform checked remainder, returning [`CtOption`] only if `rhs` is
+    /// nonzero.
+    pub fn checked_rem(&self, rhs: &Self) -> CtOption<Self> {
+        NonZero::new(*rhs).map(|rhs| self.rem(&rhs))
+    }
+
+    /// Shift `self` left by one bit, returning the result along with the bit
+    /// shifted out of the most significant limb.
+    fn shl1(&self) -> (Self, Choice) {
+        let mut limbs = [0; LIMBS];
+        let mut carry: Limb = 0;
+
+        for i in 0..LIMBS {
+            let shifted_out = self.limbs[i] >> (LIMB_BITS - 1);
+            limbs[i] = (self.limbs[i] << 1) | carry;
+            carry = shifted_out;
+        }
+
+        (Self { limbs }, Choice::from((carry as u8) & 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::U256;
+    use super::NonZero;
+
+    #[test]
+    fn div_rem_matches_native() {
+        let n = U256::from_u64(1_000_007);
+        let d = U256::from_u64(37);
+        let nz = NonZero::new(d).unwrap();
+        let (q, r) = n.div_rem(&nz);
+        assert_eq!(q, U256::from_u64(1_000_007 / 37));
+        assert_eq!(r, U256::from_u64(1_000_007 % 37));
+    }
+
+    #[test]
+    fn div_by_one_is_identity() {
+        let n = U256::from_u64(424_242);
+        let one = NonZero::new(U256::from_u64(1)).unwrap();
+        let (q, r) = n.div_rem(&one);
+        assert_eq!(q, n);
+        assert_eq!(r, U256::ZERO);
+    }
+
+    #[test]
+    fn checked_div_by_zero_is_none() {
+        let n = U256::from_u64(10);
+        assert!(bool::from(n.checked_div(&U256::ZERO).is_none()));
+        assert!(bool::from(n.checked_rem(&U256::ZERO).is_none()));
+    }
+}
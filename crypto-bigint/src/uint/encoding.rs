@@ -0,0 +1,98 @@
+//! Wire-format encodings for [`UInt`]: RLP (as used by Ethereum) and DER
+//! (as used by X.509/PKCS).
+
+extern crate alloc;
+
+pub mod der;
+pub mod rlp;
+
+use super::UInt;
+use crate::{Limb, LIMB_BYTES};
+use alloc::vec::Vec;
+
+impl<const LIMBS: usize> UInt<LIMBS> {
+    /// Big-endian bytes with all leading zero bytes stripped. The value
+    /// `0` encodes as an empty `Vec`.
+    fn to_be_bytes_trimmed(self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(LIMB_BYTES * LIMBS);
+
+        for limb in self.limbs.iter().rev() {
+            bytes.extend_from_slice(&limb.to_be_bytes());
+        }
+
+        let first_nonzero = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len());
+        bytes.split_off(first_nonzero)
+    }
+}
+
+/// Parse minimal-length (no leading zero byte), big-endian `bytes` into a
+/// [`UInt`]. Returns `None` if `bytes` is wider than `LIMBS` limbs.
+fn from_be_bytes_trimmed<const LIMBS: usize>(bytes: &[u8]) -> Option<UInt<LIMBS>> {
+    if bytes.len() > LIMB_BYTES * LIMBS {
+        return None;
+    }
+
+    let mut limbs = [0 as Limb; LIMBS];
+
+    for (i, &byte) in bytes.iter().rev().enumerate() {
+        limbs[i / LIMB_BYTES] |= (byte as Limb) << ((i % LIMB_BYTES) * 8);
+    }
+
+    Some(UInt { limbs })
+}
+
+/// Minimal-length big-endian encoding of a plain `usize`, used for the
+/// length fields of both encodings' long forms. Never empty: `0` encodes
+/// as a single zero byte.
+fn minimal_be_bytes_usize(mut n: usize) -> Vec<u8> {
+    let mut bytes = Vec::new();
+
+    while n > 0 {
+        bytes.push((n & 0xff) as u8);
+        n >>= 8;
+    }
+
+    bytes.reverse();
+
+    if bytes.is_empty() {
+        bytes.push(0);
+    }
+
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::super::U256;
+    use super::{from_be_bytes_trimmed, minimal_be_bytes_usize, Vec};
+
+    #[test]
+    fn to_be_bytes_trimmed_strips_leading_zeros() {
+        assert_eq!(U256::ZERO.to_be_bytes_trimmed(), Vec::<u8>::new());
+        assert_eq!(U256::from_u64(1).to_be_bytes_trimmed(), alloc::vec![1]);
+        assert_eq!(U256::from_u64(0x100).to_be_bytes_trimmed(), alloc::vec![1, 0]);
+    }
+
+    #[test]
+    fn from_be_bytes_trimmed_round_trips() {
+        let value = U256::from_u64(0x1234_5678);
+        let bytes = value.to_be_bytes_trimmed();
+        let decoded: U256 = from_be_bytes_trimmed(&bytes).unwrap();
+        assert_eq!(decoded, value);
+    }
+
+    #[test]
+    fn from_be_bytes_trimmed_rejects_oversized_input() {
+        let too_wide = [0xffu8; 33];
+        assert!(from_be_bytes_trimmed::<4>(&too_wide).is_none());
+    }
+
+    #[test]
+    fn minimal_be_bytes_usize_is_never_empty() {
+        assert_eq!(minimal_be_bytes_usize(0), alloc::vec![0]);
+        assert_eq!(minimal_be_bytes_usize(0xff), alloc::vec![0xff]);
+        assert_eq!(minimal_be_bytes_usize(0x100), alloc::vec![1, 0]);
+    }
+}
@@ -0,0 +1,181 @@
+//! DER (Distinguished Encoding Rules) encoding of [`UInt`] as an ASN.1
+//! `INTEGER`, as used by X.509 and PKCS.
+
+extern crate alloc;
+
+use super::super::UInt;
+use super::{from_be_bytes_trimmed, minimal_be_bytes_usize};
+use alloc::vec::Vec;
+
+/// The `INTEGER` tag.
+const INTEGER_TAG: u8 = 0x02;
+
+impl<const LIMBS: usize> UInt<LIMBS> {
+    /// Encode `self` as a DER `INTEGER`, including its tag/length header.
+    pub fn to_der(&self) -> Vec<u8> {
+        let mut body = self.to_be_bytes_trimmed();
+
+        if body.is_empty() {
+            body.push(0);
+        } else if body[0] & 0x80 != 0 {
+            // Pad with a leading zero so the high bit of the first content
+            // byte never makes the value read as negative.
+            body.insert(0, 0x00);
+        }
+
+        let mut encoded = alloc::vec![INTEGER_TAG];
+        encoded.extend_from_slice(&der_length(body.len()));
+        encoded.extend_from_slice(&body);
+        encoded
+    }
+
+    /// Decode a DER `INTEGER` into a [`UInt`].
+    ///
+    /// Returns `None` unless `bytes` is exactly one canonically-encoded,
+    /// non-negative `INTEGER` whose value fits in `LIMBS` limbs.
+    pub fn from_der(bytes: &[u8]) -> Option<Self> {
+        let (&tag, rest) = bytes.split_first()?;
+
+        if tag != INTEGER_TAG {
+            return None;
+        }
+
+        let (len, rest) = decode_der_length(rest)?;
+
+        if rest.len() != len {
+            return None; // trailing (or missing) bytes after the one encoded INTEGER
+        }
+
+        let body = rest;
+
+        if body.is_empty() {
+            return None;
+        }
+
+        // A set high bit on the first content byte marks a negative value.
+        if body[0] & 0x80 != 0 {
+            return None;
+        }
+
+        // Reject non-canonical padding: a leading zero is only legal when
+        // it's needed to keep the value from reading as negative (or it's
+        // the sole byte of a literal zero).
+        if body[0] == 0x00 && body.len() > 1 && body[1] & 0x80 == 0 {
+            return None;
+        }
+
+        let trimmed = if body[0] == 0x00 { &body[1..] } else { body };
+        from_be_bytes_trimmed(trimmed)
+    }
+}
+
+/// Build the ASN.1 length octets for a content length.
+fn der_length(len: usize) -> Vec<u8> {
+    if len < 0x80 {
+        alloc::vec![len as u8]
+    } else {
+        let len_bytes = minimal_be_bytes_usize(len);
+        let mut out = alloc::vec![0x80 | len_bytes.len() as u8];
+        out.extend_from_slice(&len_bytes);
+        out
+    }
+}
+
+/// Parse ASN.1 length octets, returning the decoded length and the
+/// remaining bytes.
+fn decode_der_length(bytes: &[u8]) -> Option<(usize, &[u8])> {
+    let (&first, rest) = bytes.split_first()?;
+
+    if first & 0x80 == 0 {
+        return Some((first as usize, rest));
+    }
+
+    let num_len_bytes = (first & 0x7f) as usize;
+
+    if num_len_bytes == 0 {
+        return None; // indefinite-length form; not valid in DER
+    }
+
+    // More length bytes than `usize` has room for would silently wrap
+    // around below instead of failing to parse; reject that up front.
+    if num_len_bytes > core::mem::size_of::<usize>() {
+        return None;
+    }
+
+    let len_bytes = rest.get(..num_len_bytes)?;
+
+    if len_bytes[0] == 0 {
+        return None; // non-canonical length encoding
+    }
+
+    let len = len_bytes
+        .iter()
+        .fold(0usize, |acc, &b| (acc << 8) | b as usize);
+
+    if len < 0x80 {
+        return None; // a length this small must use the short form
+    }
+
+    Some((len, &rest[num_len_bytes..]))
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::super::super::U256;
+    use alloc::vec;
+
+    #[test]
+    fn der_round_trips() {
+        for v in [0u64, 1, 0x7f, 0x80, 0xff, 0x1_0000, u64::MAX] {
+            let value = U256::from_u64(v);
+            let encoded = value.to_der();
+            let decoded = U256::from_der(&encoded).unwrap();
+            assert_eq!(decoded, value, "round-trip failed for {v:#x}");
+        }
+    }
+
+    #[test]
+    fn zero_encodes_as_a_single_zero_byte() {
+        assert_eq!(U256::ZERO.to_der(), vec![0x02, 0x01, 0x00]);
+    }
+
+    #[test]
+    fn high_bit_values_get_a_zero_padding_byte() {
+        // 0xff alone would read as negative in DER; it must be padded.
+        let encoded = U256::from_u64(0xff).to_der();
+        assert_eq!(encoded, vec![0x02, 0x02, 0x00, 0xff]);
+    }
+
+    #[test]
+    fn from_der_rejects_non_canonical_leading_zero() {
+        // A leading zero that isn't needed to avoid a false sign bit.
+        let bytes = vec![0x02, 0x02, 0x00, 0x7f];
+        assert!(U256::from_der(&bytes).is_none());
+    }
+
+    #[test]
+    fn from_der_rejects_trailing_bytes() {
+        let mut encoded = U256::from_u64(42).to_der();
+        encoded.push(0xaa);
+        assert!(U256::from_der(&encoded).is_none());
+    }
+
+    #[test]
+    fn from_der_rejects_wrong_tag() {
+        let mut encoded = U256::from_u64(42).to_der();
+        encoded[0] = 0x03;
+        assert!(U256::from_der(&encoded).is_none());
+    }
+
+    #[test]
+    fn from_der_rejects_oversized_length_of_length() {
+        // `0x89` claims 9 length bytes; `usize` on a 64-bit (or narrower)
+        // target can't hold that without silently truncating, so this must be rejected
+        // rather than decoded to a wrapped-around length.
+        let mut bytes = vec![0x02, 0x80 | 9];
+        bytes.extend(core::iter::repeat_n(0x01, 9));
+        assert!(U256::from_der(&bytes).is_none());
+    }
+}
@@ -0,0 +1,181 @@
+//! RLP (Recursive Length Prefix) encoding of [`UInt`], as used by Ethereum.
+
+extern crate alloc;
+
+use super::super::UInt;
+use super::{from_be_bytes_trimmed, minimal_be_bytes_usize};
+use alloc::vec::Vec;
+
+impl<const LIMBS: usize> UInt<LIMBS> {
+    /// Encode `self` as an RLP byte string.
+    pub fn to_rlp(&self) -> Vec<u8> {
+        let body = self.to_be_bytes_trimmed();
+
+        if body.len() == 1 && body[0] < 0x80 {
+            return body;
+        }
+
+        let mut encoded = rlp_length_prefix(body.len());
+        encoded.extend_from_slice(&body);
+        encoded
+    }
+
+    /// Decode an RLP byte string into a [`UInt`].
+    ///
+    /// Returns `None` unless `bytes` is exactly one canonical, minimal-length
+    /// RLP string encoding of a value that fits in `LIMBS` limbs.
+    pub fn from_rlp(bytes: &[u8]) -> Option<Self> {
+        let (&first, rest) = bytes.split_first()?;
+
+        if first < 0x80 {
+            if bytes.len() != 1 {
+                return None; // trailing bytes after the one-byte encoding
+            }
+            if first == 0 {
+                return None; // zero is encoded as the empty string, `0x80`
+            }
+            return from_be_bytes_trimmed(&bytes[..1]);
+        }
+
+        if first <= 0xb7 {
+            let len = (first - 0x80) as usize;
+
+            if rest.len() != len {
+                return None; // trailing (or missing) bytes after the one encoded string
+            }
+
+            let body = rest;
+
+            // A single byte < 0x80 must use the one-byte form above.
+            if len == 1 && body[0] < 0x80 {
+                return None;
+            }
+
+            // A minimal-length body has no leading zero byte.
+            if body.first() == Some(&0) {
+                return None;
+            }
+
+            return from_be_bytes_trimmed(body);
+        }
+
+        let len_of_len = (first - 0xb7) as usize;
+
+        // More length bytes than `usize` has room for would silently wrap
+        // around below instead of failing to parse; reject that up front.
+        if len_of_len > core::mem::size_of::<usize>() {
+            return None;
+        }
+
+        let len_bytes = rest.get(..len_of_len)?;
+
+        // Reject non-canonical length encodings.
+        if len_bytes.first() == Some(&0) {
+            return None;
+        }
+
+        let len = len_bytes
+            .iter()
+            .fold(0usize, |acc, &b| (acc << 8) | b as usize);
+
+        // A length this small should have used the short form above.
+        if len <= 55 {
+            return None;
+        }
+
+        let body = rest.get(len_of_len..)?;
+
+        if body.len() != len {
+            return None; // trailing (or missing) bytes after the one encoded string
+        }
+
+        // A minimal-length body has no leading zero byte.
+        if body.first() == Some(&0) {
+            return None;
+        }
+
+        from_be_bytes_trimmed(body)
+    }
+}
+
+/// Build the RLP length prefix for a string body of the given length.
+fn rlp_length_prefix(len: usize) -> Vec<u8> {
+    if len <= 55 {
+        alloc::vec![0x80 + len as u8]
+    } else {
+        let len_bytes = minimal_be_bytes_usize(len);
+        let mut prefix = alloc::vec![0xb7 + len_bytes.len() as u8];
+        prefix.extend_from_slice(&len_bytes);
+        prefix
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::super::super::{U2048, U256};
+    use alloc::vec;
+
+    #[test]
+    fn rlp_round_trips() {
+        for v in [0u64, 1, 0x7f, 0x80, 0xff, 0x1_0000, u64::MAX] {
+            let value = U256::from_u64(v);
+            let encoded = value.to_rlp();
+            let decoded = U256::from_rlp(&encoded).unwrap();
+            assert_eq!(decoded, value, "round-trip failed for {v:#x}");
+        }
+    }
+
+    #[test]
+    fn single_byte_below_0x80_uses_the_one_byte_form() {
+        assert_eq!(U256::from_u64(0x42).to_rlp(), vec![0x42]);
+    }
+
+    #[test]
+    fn zero_encodes_as_the_empty_string_form() {
+        assert_eq!(U256::ZERO.to_rlp(), vec![0x80]);
+    }
+
+    #[test]
+    fn short_string_form_round_trips_up_to_55_bytes() {
+        // 55 content bytes is the largest short-form body.
+        let value = U256::MAX.shl(1); // definitely more than one byte wide
+        let encoded = value.to_rlp();
+        assert!(encoded[0] <= 0xb7);
+        assert_eq!(U256::from_rlp(&encoded).unwrap(), value);
+    }
+
+    #[test]
+    fn long_string_form_round_trips_above_55_bytes() {
+        // `U2048` is wide enough to need the long (>55-byte) string form.
+        let value = U2048::MAX.shr(8);
+        let encoded = value.to_rlp();
+        assert!(encoded[0] > 0xb7);
+        assert_eq!(U2048::from_rlp(&encoded).unwrap(), value);
+    }
+
+    #[test]
+    fn from_rlp_rejects_non_canonical_single_byte_in_short_form() {
+        // A single byte < 0x80 must use the one-byte form, not `[0x81, b]`.
+        let bytes = vec![0x81, 0x42];
+        assert!(U256::from_rlp(&bytes).is_none());
+    }
+
+    #[test]
+    fn from_rlp_rejects_trailing_bytes() {
+        let mut encoded = U256::from_u64(42).to_rlp();
+        encoded.push(0xaa);
+        assert!(U256::from_rlp(&encoded).is_none());
+    }
+
+    #[test]
+    fn from_rlp_rejects_oversized_length_of_length() {
+        // `0xc0` claims 9 length bytes (`0xb7 + 9`); `usize` can't hold that
+        // many bytes without wrapping on some targets, so this must be
+        // rejected rather than silently truncated.
+        let mut bytes = vec![0xb7 + 9];
+        bytes.extend(core::iter::repeat_n(0x01, 9));
+        assert!(U256::from_rlp(&bytes).is_none());
+    }
+}
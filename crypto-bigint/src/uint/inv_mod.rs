@@ -0,0 +1,139 @@
+//! Modular multiplicative inverse via binary extended GCD.
+
+use super::UInt;
+use crate::LIMB_BYTES;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+/// Number of bits in a single [`Limb`](crate::Limb).
+const LIMB_BITS: usize = LIMB_BYTES * 8;
+
+impl<const LIMBS: usize> UInt<LIMBS> {
+    /// Compute the inverse of `self` modulo `modulus`, which must be odd.
+    ///
+    /// Returns `None` (via [`CtOption`]) if `self` and `modulus` are not
+    /// coprime. Implements the constant-time binary extended GCD: `(a, u)`
+    /// starts at `(self, 1)` and `(b, v)` at `(modulus, 0)`, and every step
+    /// -- halving on an even `a`, or swapping and subtracting on an odd one
+    /// -- is chosen with `conditional_select` rather than a branch, so
+    /// control flow never depends on the operands. `a` is driven down to `0`
+    /// by this process (never `1`), with the gcd ending up in `b` and the
+    /// matching Bezout coefficient in `v`; those are what get returned.
+    ///
+    /// Runs for `4 * LIMBS * LIMB_BITS` steps rather than `2 * LIMBS *
+    /// LIMB_BITS`: each step only ever reduces `a` by one bit (a halving) or
+    /// performs one swap-subtract, and the worst case -- `self` a power of
+    /// two, `modulus` all-ones -- needs noticeably more than `2 * bits`
+    /// steps to fully converge (empirically closer to `2.8 * bits` and
+    /// climbing with width), so `2 * bits` silently truncates the loop
+    /// before `a` reaches `0` for some odd moduli.
+    pub fn inv_mod(&self, modulus: &Self) -> CtOption<Self> {
+        let mut a = *self;
+        let mut b = *modulus;
+        let mut u = Self::ONE;
+        let mut v = Self::ZERO;
+
+        let mut i = 0;
+        while i < 4 * LIMBS * LIMB_BITS {
+            let a_even = !a.bit(0);
+
+            // Even step: halve `a`, and halve `u` alongside it modulo
+            // `modulus` (adding `modulus` first if `u` is odd, so the
+            // halving doesn't lose the low bit). `u + modulus` can itself
+            // overflow the `LIMBS`-limb representation (both are `<
+            // modulus`, and a real `modulus` routinely fills its `UInt`'s
+            // top bit), so track that overflow explicitly via `adc` instead
+            // of `wrapping_add`, and fold the dropped carry bit back in as
+            // the halved value's new top bit.
+            let (u_sum, u_carry) = u.adc(modulus, 0);
+            let u_even = Self::conditional_select(&u, &u_sum, u.bit(0));
+            let halved_a = a.shr(1);
+            let carry_in = Choice::from((u_carry as u8) & 1) & u.bit(0);
+            let halved_u = Self::conditional_select(
+                &u_even.shr(1),
+                &u_even.shr(1).bitor(&Self::ONE.shl(LIMBS * LIMB_BITS - 1)),
+                carry_in,
+            );
+
+            // Odd step: swap `(a, u)` and `(b, v)` so that `a >= b`, then
+            // subtract. A subtraction that borrows is corrected by adding
+            // `modulus` back in, keeping `u` in its residue class.
+            let need_swap = a.ct_lt(&b);
+            let a_hi = Self::conditional_select(&a, &b, need_swap);
+            let b_hi = Self::conditional_select(&b, &a, need_swap);
+            let u_hi = Self::conditional_select(&u, &v, need_swap);
+            let v_hi = Self::conditional_select(&v, &u, need_swap);
+
+            let sub_a = a_hi.wrapping_sub(&b_hi);
+            let (sub_u_diff, borrow) = u_hi.sbb(&v_hi, 0);
+            let sub_u = Self::conditional_select(
+                &sub_u_diff,
+                &sub_u_diff.wrapping_add(modulus),
+                Choice::from((borrow as u8) & 1),
+            );
+
+            a = Self::conditional_select(&sub_a, &halved_a, a_even);
+            b = Self::conditional_select(&b_hi, &b, a_even);
+            u = Self::conditional_select(&sub_u, &halved_u, a_even);
+            v = Self::conditional_select(&v_hi, &v, a_even);
+
+            i += 1;
+        }
+
+        CtOption::new(v, b.ct_eq(&Self::ONE))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{NonZero, U256};
+    use crate::{Concat, Split};
+    use subtle::ConstantTimeEq;
+
+    #[test]
+    fn inv_mod_is_multiplicative_inverse_mod_97() {
+        let modulus = U256::from_u64(97);
+
+        for v in 1u64..97 {
+            let a = U256::from_u64(v);
+            let inv = a.inv_mod(&modulus);
+            assert!(bool::from(inv.is_some()), "expected inverse for {v}");
+
+            let inv = inv.unwrap();
+            let (hi, lo) = a.mul_wide(&inv);
+            assert!(bool::from(hi.is_zero()));
+            assert_eq!(lo.rem(&NonZero::new(modulus).unwrap()), U256::ONE);
+        }
+    }
+
+    #[test]
+    fn inv_mod_rejects_non_coprime_values() {
+        let modulus = U256::from_u64(99);
+        let a = U256::from_u64(33); // gcd(33, 99) == 33
+        assert!(bool::from(a.inv_mod(&modulus).is_none()));
+    }
+
+    // Regression test for a modulus that fills the upper half of `U256`'s bit
+    // range: the even step's `u + modulus` overflows the `U256` representation
+    // here, which an earlier version of `inv_mod` silently dropped.
+    #[test]
+    fn inv_mod_is_correct_for_full_width_modulus() {
+        let modulus = U256::from_be_hex(
+            "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF43",
+        );
+        let a = U256::from_be_hex(
+            "089ABCDEF0123456789ABCDEF0123456789ABCDEF0123456789ABCDEF0123456",
+        );
+
+        let inv = a.inv_mod(&modulus);
+        assert!(bool::from(inv.is_some()));
+        let inv = inv.unwrap();
+
+        let (hi, lo) = a.mul_wide(&inv);
+        let wide_product = hi.concat(&lo);
+        let wide_modulus = U256::ZERO.concat(&modulus);
+        let (_, wide_remainder) = wide_product.div_rem(&NonZero::new(wide_modulus).unwrap());
+        let (_, remainder) = wide_remainder.split();
+
+        assert!(bool::from(remainder.ct_eq(&U256::ONE)));
+    }
+}
@@ -0,0 +1,320 @@
+//! Montgomery-form modular arithmetic for [`UInt`].
+//!
+//! Provides constant-time modular multiplication and exponentiation over a
+//! fixed, odd modulus using the CIOS (Coarsely Integrated Operand Scanning)
+//! Montgomery multiplication algorithm.
+
+use super::UInt;
+use crate::{limb, Limb, LIMB_BYTES};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+/// Number of bits in a single [`Limb`].
+const LIMB_BITS: usize = LIMB_BYTES * 8;
+
+/// Precomputed Montgomery arithmetic parameters for a fixed, odd modulus.
+#[derive(Copy, Clone, Debug)]
+pub struct MontgomeryParams<const LIMBS: usize> {
+    /// The modulus `M` itself.
+    modulus: UInt<LIMBS>,
+
+    /// `R = 2^(LIMBS * LIMB_BITS) mod M`.
+    r: UInt<LIMBS>,
+
+    /// `R^2 mod M`, used to carry values into Montgomery form.
+    r2: UInt<LIMBS>,
+
+    /// `M' = -M^-1 mod 2^LIMB_BITS`, the Montgomery reduction constant.
+    mod_neg_inv: Limb,
+}
+
+impl<const LIMBS: usize> MontgomeryParams<LIMBS> {
+    /// Derive the Montgomery parameters for `modulus`, which must be odd.
+    pub fn new(modulus: UInt<LIMBS>) -> Self {
+        let bits = LIMBS * LIMB_BITS;
+        let r = pow2_mod(bits, &modulus);
+        let r2 = pow2_mod(2 * bits, &modulus);
+        let mod_neg_inv = hensel_inv(modulus.limbs()[0]).wrapping_neg();
+
+        Self {
+            modulus,
+            r,
+            r2,
+            mod_neg_inv,
+        }
+    }
+}
+
+/// A value held in Montgomery form modulo a fixed [`MontgomeryParams`].
+///
+/// All arithmetic on [`Residue`] runs in constant time with respect to the
+/// values involved (though not the modulus, which is assumed public).
+#[derive(Copy, Clone, Debug)]
+pub struct Residue<const LIMBS: usize> {
+    params: MontgomeryParams<LIMBS>,
+    montgomery_form: UInt<LIMBS>,
+}
+
+impl<const LIMBS: usize> Residue<LIMBS> {
+    /// Convert `value` into Montgomery form under `params`.
+    pub fn new(value: &UInt<LIMBS>, params: MontgomeryParams<LIMBS>) -> Self {
+        let montgomery_form = montgomery_mul(
+            value,
+            &params.r2,
+            &params.modulus,
+            params.mod_neg_inv,
+        );
+
+        Self {
+            params,
+            montgomery_form,
+        }
+    }
+
+    /// The multiplicative identity, `1`, in Montgomery form.
+    fn one(params: MontgomeryParams<LIMBS>) -> Self {
+        Self {
+            params,
+            montgomery_form: params.r,
+        }
+    }
+
+    /// Convert back out of Montgomery form.
+    pub fn retrieve(&self) -> UInt<LIMBS> {
+        montgomery_mul(
+            &self.montgomery_form,
+            &UInt::ONE,
+            &self.params.modulus,
+            self.params.mod_neg_inv,
+        )
+    }
+
+    /// Multiply two residues modulo the shared modulus.
+    pub fn mul(&self, rhs: &Self) -> Self {
+        let montgomery_form = montgomery_mul(
+            &self.montgomery_form,
+            &rhs.montgomery_form,
+            &self.params.modulus,
+            self.params.mod_neg_inv,
+        );
+
+        Self {
+            params: self.params,
+            montgomery_form,
+        }
+    }
+
+    /// Add two residues modulo the shared modulus.
+    pub fn add_mod(&self, rhs: &Self) -> Self {
+        let (sum, carry) = self.montgomery_form.adc(&rhs.montgomery_form, 0);
+        let (diff, borrow) = sum.sbb(&self.params.modulus, 0);
+        let need_sub = Choice::from((carry != 0) as u8) | !Choice::from((borrow as u8) & 1);
+
+        Self {
+            params: self.params,
+            montgomery_form: UInt::conditional_select(&sum, &diff, need_sub),
+        }
+    }
+
+    /// Subtract `rhs` from `self` modulo the shared modulus.
+    pub fn sub_mod(&self, rhs: &Self) -> Self {
+        let (diff, borrow) = self.montgomery_form.sbb(&rhs.montgomery_form, 0);
+        let borrowed = Choice::from((borrow as u8) & 1);
+        let corrected = diff.wrapping_add(&self.params.modulus);
+
+        Self {
+            params: self.params,
+            montgomery_form: UInt::conditional_select(&diff, &corrected, borrowed),
+        }
+    }
+
+    /// Negate `self` modulo the shared modulus.
+    pub fn neg_mod(&self) -> Self {
+        let zero = Self {
+            params: self.params,
+            montgomery_form: UInt::ZERO,
+        };
+
+        zero.sub_mod(self)
+    }
+
+    /// Raise `self` to `exponent`, via constant-time square-and-multiply.
+    pub fn pow(&self, exponent: &UInt<LIMBS>) -> Self {
+        let mut result = Self::one(self.params);
+        let mut base = *self;
+
+        let mut i = 0;
+        while i < LIMBS * LIMB_BITS {
+            let multiplied = result.mul(&base);
+            result = Self::conditional_select(&result, &multiplied, exponent.bit(i));
+            base = base.mul(&base);
+            i += 1;
+        }
+
+        result
+    }
+}
+
+impl<const LIMBS: usize> ConditionallySelectable for Residue<LIMBS> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self {
+            params: a.params,
+            montgomery_form: UInt::conditional_select(&a.montgomery_form, &b.montgomery_form, choice),
+        }
+    }
+}
+
+/// Compute `2^bits mod modulus` via constant-time double-and-reduce.
+fn pow2_mod<const LIMBS: usize>(bits: usize, modulus: &UInt<LIMBS>) -> UInt<LIMBS> {
+    let mut acc = UInt::ONE;
+
+    let mut i = 0;
+    while i < bits {
+        let (doubled, carry) = acc.adc(&acc, 0);
+        let (diff, borrow) = doubled.sbb(modulus, 0);
+        let need_sub = Choice::from((carry != 0) as u8) | !Choice::from((borrow as u8) & 1);
+        acc = UInt::conditional_select(&doubled, &diff, need_sub);
+        i += 1;
+    }
+
+    acc
+}
+
+/// Compute the inverse of odd `x` modulo `2^LIMB_BITS`, via Hensel lifting:
+/// starting from the 1-bit-accurate inverse `1`, each iteration of
+/// `y *= 2 - x*y` doubles the number of correct low bits.
+fn hensel_inv(x: Limb) -> Limb {
+    let mut inv: Limb = 1;
+    let mut correct_bits = 1;
+
+    while correct_bits < LIMB_BITS {
+        inv = inv.wrapping_mul((2 as Limb).wrapping_sub(x.wrapping_mul(inv)));
+        correct_bits *= 2;
+    }
+
+    inv
+}
+
+/// CIOS Montgomery multiplication: computes `a * b * R^-1 mod modulus`.
+fn montgomery_mul<const LIMBS: usize>(
+    a: &UInt<LIMBS>,
+    b: &UInt<LIMBS>,
+    modulus: &UInt<LIMBS>,
+    mod_neg_inv: Limb,
+) -> UInt<LIMBS> {
+    let mut acc = [0 as Limb; LIMBS];
+    let mut acc_hi: Limb = 0;
+
+    for i in 0..LIMBS {
+        // acc += a * b[i]
+        let mut carry: Limb = 0;
+        for j in 0..LIMBS {
+            let (w, c) = limb::mac(acc[j], a.limbs()[j], b.limbs()[i], carry);
+            acc[j] = w;
+            carry = c;
+        }
+        let (new_hi, carry_out_a) = limb::adc(acc_hi, 0, carry);
+        acc_hi = new_hi;
+
+        // m = acc[0] * M' mod 2^LIMB_BITS, chosen so that acc + m*modulus
+        // is divisible by 2^LIMB_BITS.
+        let m = acc[0].wrapping_mul(mod_neg_inv);
+
+        // acc += m * modulus
+        let mut carry: Limb = 0;
+        for j in 0..LIMBS {
+            let (w, c) = limb::mac(acc[j], m, modulus.limbs()[j], carry);
+            acc[j] = w;
+            carry = c;
+        }
+        let (new_hi, carry_out_b) = limb::adc(acc_hi, 0, carry);
+        acc_hi = new_hi;
+
+        // Shift the accumulator down by one limb; `acc[0]` is zero by
+        // construction of `m` and is simply dropped.
+        for j in 0..LIMBS - 1 {
+            acc[j] = acc[j + 1];
+        }
+        acc[LIMBS - 1] = acc_hi;
+        acc_hi = carry_out_a + carry_out_b;
+    }
+
+    let result = UInt { limbs: acc };
+    let hi_nonzero = !acc_hi.ct_eq(&0);
+    let (diff, borrow) = result.sbb(modulus, 0);
+    let need_sub = hi_nonzero | !Choice::from((borrow as u8) & 1);
+
+    UInt::conditional_select(&result, &diff, need_sub)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::U256;
+    use super::{MontgomeryParams, Residue};
+
+    #[test]
+    fn retrieve_undoes_new() {
+        let modulus = U256::from_u64(97);
+        let params = MontgomeryParams::<4>::new(modulus);
+
+        for v in 0u64..97 {
+            let value = U256::from_u64(v);
+            let residue = Residue::new(&value, params);
+            assert_eq!(residue.retrieve(), value);
+        }
+    }
+
+    #[test]
+    fn mul_matches_native() {
+        let modulus = U256::from_u64(97);
+        let params = MontgomeryParams::<4>::new(modulus);
+
+        for v in 1u64..97 {
+            for w in 1u64..97 {
+                let rv = Residue::new(&U256::from_u64(v), params);
+                let rw = Residue::new(&U256::from_u64(w), params);
+                let got = rv.mul(&rw).retrieve();
+                assert_eq!(got, U256::from_u64((v * w) % 97));
+            }
+        }
+    }
+
+    #[test]
+    fn add_sub_neg_match_native() {
+        let modulus = U256::from_u64(97);
+        let params = MontgomeryParams::<4>::new(modulus);
+
+        for v in 0u64..97 {
+            for w in 0u64..97 {
+                let rv = Residue::new(&U256::from_u64(v), params);
+                let rw = Residue::new(&U256::from_u64(w), params);
+
+                let sum = rv.add_mod(&rw).retrieve();
+                assert_eq!(sum, U256::from_u64((v + w) % 97));
+
+                let diff = rv.sub_mod(&rw).retrieve();
+                assert_eq!(diff, U256::from_u64((v + 97 - w) % 97));
+            }
+
+            let neg = Residue::new(&U256::from_u64(v), params).neg_mod().retrieve();
+            assert_eq!(neg, U256::from_u64((97 - v) % 97));
+        }
+    }
+
+    #[test]
+    fn pow_matches_native() {
+        let modulus = U256::from_u64(97);
+        let params = MontgomeryParams::<4>::new(modulus);
+
+        for base in 1u64..20 {
+            for exp in 0u64..10 {
+                let r = Residue::new(&U256::from_u64(base), params);
+                let got = r.pow(&U256::from_u64(exp)).retrieve();
+                let mut expected = 1u64;
+                for _ in 0..exp {
+                    expected = (expected * base) % 97;
+                }
+                assert_eq!(got, U256::from_u64(expected));
+            }
+        }
+    }
+}
@@ -0,0 +1,63 @@
+//! A wrapper type for values known to be nonzero.
+
+use core::ops::Deref;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+/// Wrapper type for non-zero values.
+///
+/// Guarantees that the wrapped value is never `0`, allowing operations that
+/// would otherwise have to return [`CtOption`] (such as division) to skip
+/// the zero check and return a bare result instead.
+///
+/// `Default` is derived (rather than omitted) purely so [`NonZero`] can
+/// itself be wrapped in a [`CtOption`], whose branchless combinators require
+/// a dummy value to conditionally select against; it does not imply `0` is a
+/// valid [`NonZero`] value.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct NonZero<T>(T);
+
+impl<T> NonZero<T>
+where
+    T: ConstantTimeEq + Default,
+{
+    /// Create a new [`NonZero`], returning `None` (via [`CtOption`]) if the
+    /// given value is zero.
+    pub fn new(n: T) -> CtOption<Self> {
+        let is_nonzero = !n.ct_eq(&T::default());
+        CtOption::new(Self(n), is_nonzero)
+    }
+}
+
+impl<T> NonZero<T> {
+    /// Create a new [`NonZero`] from a value which is known, by construction,
+    /// to be nonzero.
+    ///
+    /// Callers are responsible for upholding this invariant: operations
+    /// built on top of [`NonZero`] (e.g. division) assume it holds and may
+    /// produce incorrect results if it does not.
+    pub const fn new_unchecked(n: T) -> Self {
+        Self(n)
+    }
+
+    /// Return the inner value.
+    pub fn get(self) -> T
+    where
+        T: Copy,
+    {
+        self.0
+    }
+}
+
+impl<T> Deref for NonZero<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: ConditionallySelectable> ConditionallySelectable for NonZero<T> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(T::conditional_select(&a.0, &b.0, choice))
+    }
+}
@@ -0,0 +1,111 @@
+//! `rand_core`-backed random generation for [`UInt`].
+
+use super::{NonZero, UInt};
+use crate::{Limb, LIMB_BYTES};
+use rand_core::RngCore;
+
+/// Number of bits in a single [`Limb`].
+const LIMB_BITS: usize = LIMB_BYTES * 8;
+
+impl<const LIMBS: usize> UInt<LIMBS> {
+    /// Generate a random [`UInt`], filling every limb with fresh entropy
+    /// from `rng`.
+    pub fn random(rng: &mut impl RngCore) -> Self {
+        let mut limbs = [0; LIMBS];
+
+        for limb in limbs.iter_mut() {
+            let mut bytes = [0u8; LIMB_BYTES];
+            rng.fill_bytes(&mut bytes);
+            *limb = Limb::from_le_bytes(bytes);
+        }
+
+        Self { limbs }
+    }
+
+    /// Generate a [`UInt`] uniformly distributed in `[0, modulus)`.
+    ///
+    /// Uses rejection sampling: a candidate is drawn and masked down to
+    /// `modulus`'s bit length, then redrawn until it lands below `modulus`.
+    pub fn random_mod(rng: &mut impl RngCore, modulus: &NonZero<Self>) -> Self {
+        let mask_bits = modulus.bits();
+
+        loop {
+            let candidate = Self::random(rng).mask_high_bits(mask_bits);
+
+            if candidate.ct_lt(modulus).into() {
+                return candidate;
+            }
+        }
+    }
+
+    /// Zero out every bit at or above position `bits`.
+    fn mask_high_bits(&self, bits: usize) -> Self {
+        let full_limbs = bits / LIMB_BITS;
+        let rem_bits = bits % LIMB_BITS;
+        let mut limbs = [0; LIMBS];
+
+        for i in 0..LIMBS {
+            if i < full_limbs {
+                limbs[i] = self.limbs[i];
+            } else if i == full_limbs && rem_bits > 0 {
+                let mask: Limb = (1 << rem_bits) - 1;
+                limbs[i] = self.limbs[i] & mask;
+            }
+        }
+
+        Self { limbs }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{NonZero, U256};
+    use rand_core::{Error, RngCore};
+
+    /// A deterministic xorshift64* PRNG, used only so these tests don't
+    /// depend on a particular `rand_core`-compatible crate being available.
+    struct DeterministicRng(u64);
+
+    impl RngCore for DeterministicRng {
+        fn next_u32(&mut self) -> u32 {
+            self.next_u64() as u32
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            for chunk in dest.chunks_mut(8) {
+                let bytes = self.next_u64().to_le_bytes();
+                chunk.copy_from_slice(&bytes[..chunk.len()]);
+            }
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn random_mod_stays_below_modulus() {
+        let mut rng = DeterministicRng(0x0123_4567_89ab_cdef);
+        let modulus = NonZero::new(U256::from_u64(97)).unwrap();
+
+        for _ in 0..200 {
+            let v = U256::random_mod(&mut rng, &modulus);
+            assert!(bool::from(v.ct_lt(&modulus)));
+        }
+    }
+
+    #[test]
+    fn mask_high_bits_clears_everything_at_or_above_bits() {
+        let all_ones = U256::MAX;
+        assert_eq!(all_ones.mask_high_bits(0), U256::ZERO);
+        assert_eq!(all_ones.mask_high_bits(4), U256::from_u64(0xf));
+    }
+}
@@ -0,0 +1,86 @@
+//! Integer square root for [`UInt`].
+
+use super::UInt;
+use crate::LIMB_BYTES;
+use subtle::ConditionallySelectable;
+
+/// Number of bits in a single [`Limb`](crate::Limb).
+const LIMB_BITS: usize = LIMB_BYTES * 8;
+
+impl<const LIMBS: usize> UInt<LIMBS> {
+    /// Compute the floor of the square root of `self`.
+    ///
+    /// Uses Newton's method, starting from the next power of two at or
+    /// above the true root and iterating a fixed number of times so that
+    /// the running time does not depend on `self`'s magnitude. A final
+    /// constant-time correction step accounts for Newton's method
+    /// occasionally overshooting by one.
+    pub fn sqrt(&self) -> Self {
+        let mut x = Self::ONE.shl(self.bits().div_ceil(2));
+        let mut min = x;
+
+        let mut i = 0;
+        while i < newton_iterations(LIMBS * LIMB_BITS) {
+            let quotient = self.checked_div(&x).unwrap_or(Self::ZERO);
+            let (sum, _carry) = x.adc(&quotient, 0);
+            let next = sum.shr(1);
+
+            min = Self::conditional_select(&min, &next, next.ct_lt(&min));
+            x = next;
+            i += 1;
+        }
+
+        // `min` may still be one too large; correct for that in constant
+        // time by checking whether its square exceeds `self`.
+        let (hi, lo) = min.mul_wide(&min);
+        let too_big = !hi.is_zero() | lo.ct_gt(self);
+        let corrected = min.wrapping_sub(&Self::ONE);
+        let result = Self::conditional_select(&min, &corrected, too_big);
+
+        Self::conditional_select(&result, &Self::ZERO, self.is_zero())
+    }
+}
+
+/// Number of Newton iterations needed to converge on an integer square
+/// root of a value with `bits` bits: `ceil(log2(bits))`.
+fn newton_iterations(bits: usize) -> usize {
+    let mut n = 0;
+    let mut v = 1;
+
+    while v < bits {
+        v *= 2;
+        n += 1;
+    }
+
+    n.max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::U256;
+
+    #[test]
+    fn sqrt_matches_native_for_small_values() {
+        for v in 0u64..2000 {
+            let mut native = 0u64;
+            while (native + 1) * (native + 1) <= v {
+                native += 1;
+            }
+
+            let got = U256::from_u64(v).sqrt();
+            assert_eq!(got, U256::from_u64(native), "sqrt mismatch for {v}");
+        }
+    }
+
+    #[test]
+    fn sqrt_of_zero_and_one() {
+        assert_eq!(U256::ZERO.sqrt(), U256::ZERO);
+        assert_eq!(U256::ONE.sqrt(), U256::ONE);
+    }
+
+    #[test]
+    fn sqrt_of_perfect_square_is_exact() {
+        let n = U256::from_u64(1_000_003 * 1_000_003);
+        assert_eq!(n.sqrt(), U256::from_u64(1_000_003));
+    }
+}